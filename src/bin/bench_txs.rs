@@ -0,0 +1,245 @@
+//! Synthetic transaction load generator for dev and test networks.
+//!
+//! Signs EIP-1559 transfers, or contract calls when `--input` is given, and submits them over
+//! JSON-RPC at a target rate, then waits for them to be included and reports the achieved
+//! throughput.
+//!
+//! The pool keeps a limited number of pending transactions per sender, so high rates need
+//! several funded keys: pass `--private-key` once per key and transactions are spread across
+//! them round-robin.
+
+use std::{
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
+
+use alloy_consensus::{SignableTransaction, TxEip1559, TxEnvelope};
+use alloy_eips::eip2718::Encodable2718;
+use alloy_primitives::{hex, Address, Bytes, TxKind, U256};
+use alloy_signer::SignerSync;
+use alloy_signer_local::PrivateKeySigner;
+use anyhow::{bail, Context};
+use clap::Parser;
+use serde_json::{json, Value};
+
+/// Gas limit of a plain transfer.
+const TRANSFER_GAS: u64 = 21_000;
+
+/// Gas limit used for contract calls unless overridden.
+const CALL_GAS: u64 = 200_000;
+
+#[derive(Debug, Parser)]
+#[command(about = "Submit signed transactions to a node at a target rate")]
+struct Args {
+    /// JSON-RPC endpoint of the node.
+    #[arg(long, default_value = "http://localhost:8545")]
+    rpc_url: String,
+
+    /// Hex-encoded private key of a funded sender. Can be repeated.
+    #[arg(long = "private-key", value_delimiter = ',', required = true)]
+    private_keys: Vec<PrivateKeySigner>,
+
+    /// Number of transactions to submit.
+    #[arg(long, default_value_t = 1_000)]
+    count: u64,
+
+    /// Target submission rate, in transactions per second.
+    #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u64).range(1..=1_000_000_000))]
+    tps: u64,
+
+    /// Recipient of the transactions. Defaults to each sender itself.
+    #[arg(long)]
+    to: Option<Address>,
+
+    /// Value to send with each transaction, in wei.
+    #[arg(long, default_value_t = U256::ZERO)]
+    value: U256,
+
+    /// Calldata for contract calls. Plain transfers are sent if omitted.
+    #[arg(long)]
+    input: Option<Bytes>,
+
+    /// Gas limit of each transaction. Defaults to 21000 for transfers and 200000 for calls.
+    #[arg(long)]
+    gas_limit: Option<u64>,
+
+    /// Priority fee per gas, in wei.
+    #[arg(long, default_value_t = 1_000_000_000)]
+    priority_fee: u128,
+
+    /// How long to wait for submitted transactions to be included, in seconds.
+    #[arg(long, default_value_t = 120)]
+    inclusion_timeout: u64,
+}
+
+/// A sender with the next nonce to use.
+#[derive(Debug)]
+struct Sender {
+    signer: PrivateKeySigner,
+    next_nonce: u64,
+}
+
+/// Minimal JSON-RPC client over HTTP.
+#[derive(Debug, Clone)]
+struct RpcClient {
+    client: reqwest::Client,
+    url: String,
+    id: Arc<AtomicU64>,
+}
+
+impl RpcClient {
+    fn new(url: String) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            url,
+            id: Default::default(),
+        }
+    }
+
+    async fn request(&self, method: &str, params: Value) -> anyhow::Result<Value> {
+        let id = self.id.fetch_add(1, Ordering::Relaxed);
+        let mut response = self
+            .client
+            .post(&self.url)
+            .json(&json!({ "jsonrpc": "2.0", "id": id, "method": method, "params": params }))
+            .send()
+            .await
+            .with_context(|| format!("{method} request failed"))?
+            .error_for_status()?
+            .json::<Value>()
+            .await?;
+
+        if let Some(error) = response.get("error") {
+            bail!("{method} failed: {error}");
+        }
+        Ok(response["result"].take())
+    }
+
+    async fn request_u64(&self, method: &str, params: Value) -> anyhow::Result<u64> {
+        let result = self.request(method, params).await?;
+        let hex = result
+            .as_str()
+            .and_then(|s| s.strip_prefix("0x"))
+            .with_context(|| format!("{method} returned a non-quantity: {result}"))?;
+        Ok(u64::from_str_radix(hex, 16)?)
+    }
+
+    async fn nonce(&self, address: Address, tag: &str) -> anyhow::Result<u64> {
+        self.request_u64("eth_getTransactionCount", json!([address, tag]))
+            .await
+    }
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let args = Args::parse();
+
+    let rpc = RpcClient::new(args.rpc_url.clone());
+    let chain_id = rpc.request_u64("eth_chainId", json!([])).await?;
+    let gas_price = rpc.request_u64("eth_gasPrice", json!([])).await? as u128;
+    // Leave room for the base fee to double before the transactions are included
+    let max_fee_per_gas = gas_price * 2 + args.priority_fee;
+    let gas_limit = args.gas_limit.unwrap_or(if args.input.is_some() {
+        CALL_GAS
+    } else {
+        TRANSFER_GAS
+    });
+
+    let mut senders = Vec::with_capacity(args.private_keys.len());
+    for signer in args.private_keys {
+        let next_nonce = rpc.nonce(signer.address(), "pending").await?;
+        senders.push(Sender { signer, next_nonce });
+    }
+    let start_block = rpc.request_u64("eth_blockNumber", json!([])).await?;
+
+    println!(
+        "Submitting {} transactions from {} senders at {} tx/s to chain {chain_id}",
+        args.count,
+        senders.len(),
+        args.tps
+    );
+
+    let mut interval = tokio::time::interval(Duration::from_nanos(1_000_000_000 / args.tps));
+    let mut submissions = Vec::with_capacity(args.count as usize);
+    let started = Instant::now();
+    for i in 0..args.count {
+        interval.tick().await;
+
+        let num_senders = senders.len();
+        let sender = &mut senders[i as usize % num_senders];
+        let tx = TxEip1559 {
+            chain_id,
+            nonce: sender.next_nonce,
+            gas_limit,
+            max_fee_per_gas,
+            max_priority_fee_per_gas: args.priority_fee,
+            to: TxKind::Call(args.to.unwrap_or(sender.signer.address())),
+            value: args.value,
+            input: args.input.clone().unwrap_or_default(),
+            ..Default::default()
+        };
+        let signature = sender.signer.sign_hash_sync(&tx.signature_hash())?;
+        let raw = TxEnvelope::from(tx.into_signed(signature)).encoded_2718();
+        sender.next_nonce += 1;
+
+        let rpc = rpc.clone();
+        submissions.push(tokio::spawn(async move {
+            rpc.request("eth_sendRawTransaction", json!([hex::encode_prefixed(raw)]))
+                .await
+        }));
+    }
+
+    let mut failed = 0;
+    for submission in submissions {
+        if let Err(err) = submission.await? {
+            failed += 1;
+            eprintln!("{err:#}");
+        }
+    }
+    let submit_elapsed = started.elapsed();
+    println!(
+        "Submitted {} transactions in {submit_elapsed:.2?} ({:.1} tx/s), {failed} rejected",
+        args.count,
+        args.count as f64 / submit_elapsed.as_secs_f64()
+    );
+    if failed > 0 {
+        // A rejected transaction leaves a nonce gap that blocks all later ones of its sender
+        bail!("{failed} transactions were rejected, not waiting for inclusion");
+    }
+
+    // Transactions are included once every sender's confirmed nonce has caught up
+    let deadline = started + Duration::from_secs(args.inclusion_timeout);
+    loop {
+        let mut included = true;
+        for sender in &senders {
+            if rpc.nonce(sender.signer.address(), "latest").await? < sender.next_nonce {
+                included = false;
+                break;
+            }
+        }
+        if included {
+            break;
+        }
+        if Instant::now() > deadline {
+            bail!(
+                "transactions not included after {}s",
+                args.inclusion_timeout
+            );
+        }
+        tokio::time::sleep(Duration::from_millis(500)).await;
+    }
+
+    let elapsed = started.elapsed();
+    let end_block = rpc.request_u64("eth_blockNumber", json!([])).await?;
+    println!(
+        "Included {} transactions in {elapsed:.2?} ({:.1} tx/s) over blocks {}..={end_block}",
+        args.count,
+        args.count as f64 / elapsed.as_secs_f64(),
+        start_block + 1
+    );
+
+    Ok(())
+}
@@ -1,15 +1,9 @@
 use std::borrow::Cow;
 
 use alloy_consensus::{Transaction, TxReceipt};
-use alloy_eips::eip7002::WITHDRAWAL_REQUEST_TYPE;
-use alloy_eips::eip7251;
-use alloy_eips::{eip7685::Requests, Encodable2718};
+use alloy_eips::Encodable2718;
 use alloy_evm::block::ExecutableTx;
-use alloy_evm::{
-    block::state_changes::balance_increment_state,
-    eth::eip6110::{self, parse_deposits_from_receipts},
-    FromTxWithEncoded,
-};
+use alloy_evm::{block::state_changes::balance_increment_state, FromTxWithEncoded};
 use alloy_evm::{Database, Evm};
 use reth_errors::{BlockExecutionError, BlockValidationError};
 use reth_evm::block::CommitChanges;
@@ -35,7 +29,7 @@ use revm_database::State;
 use revm_primitives::{Address, Log};
 
 use crate::evm::factory::GnosisEvmFactory;
-use crate::gnosis::apply_post_block_system_calls;
+use crate::gnosis::apply_post_execution_calls;
 
 // REF: https://github.com/alloy-rs/evm/blob/99d5b552c131e3419448c214e09474bf4f0d1e4b/crates/op-evm/src/block/mod.rs#L42
 /// Block executor for Ethereum.
@@ -183,53 +177,17 @@ where
         let withdrawals = self.ctx.withdrawals.as_deref();
         let beneficiary = self.evm.block().beneficiary();
 
-        let requests = if self
-            .spec
-            .is_prague_active_at_timestamp(self.evm.block().timestamp.to())
-        {
-            // Collect all EIP-6110 deposits
-            let deposit_requests = parse_deposits_from_receipts(&self.spec, &self.receipts)?;
-
-            let mut requests = Requests::default();
-
-            if !deposit_requests.is_empty() {
-                requests.push_request_with_type(eip6110::DEPOSIT_REQUEST_TYPE, deposit_requests);
-            }
-
-            let withdrawal_requests = self
-                .system_caller
-                .apply_withdrawal_requests_contract_call(&mut self.evm)?;
-            if !withdrawal_requests.is_empty() {
-                requests.push_request_with_type(WITHDRAWAL_REQUEST_TYPE, withdrawal_requests);
-            }
-
-            // Collect all EIP-7251 requests
-            let consolidation_requests = self
-                .system_caller
-                .apply_consolidation_requests_contract_call(&mut self.evm)?;
-            if !consolidation_requests.is_empty() {
-                requests.push_request_with_type(
-                    eip7251::CONSOLIDATION_REQUEST_TYPE,
-                    consolidation_requests,
-                );
-            }
-            requests
-        } else {
-            Requests::default()
-        };
-
-        // Gnosis-specific // Start
-        let (balance_increments, _) = apply_post_block_system_calls(
+        let (requests, balance_increments) = apply_post_execution_calls(
             &self.spec,
             self.block_rewards_address,
             deposit_contract,
             timestamp.to(),
             withdrawals,
             beneficiary,
+            &self.receipts,
             &mut self.evm,
             &mut self.system_caller,
         )?;
-        // Gnosis-specific // End
 
         // increment balances
         self.evm
@@ -319,6 +277,11 @@ impl<R, Spec, EvmFactory> GnosisBlockExecutorFactory<R, Spec, EvmFactory> {
     pub const fn evm_factory(&self) -> &EvmFactory {
         &self.evm_factory
     }
+
+    /// Exposes the address of the block rewards contract.
+    pub const fn block_rewards_address(&self) -> Address {
+        self.block_rewards_address
+    }
 }
 
 impl<R, Spec, EvmF> BlockExecutorFactory for GnosisBlockExecutorFactory<R, Spec, EvmF>
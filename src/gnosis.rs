@@ -1,8 +1,10 @@
 use crate::errors::GnosisBlockExecutionError;
-use alloy_consensus::constants::KECCAK_EMPTY;
+use alloy_consensus::{constants::KECCAK_EMPTY, TxReceipt};
 use alloy_eips::eip4895::{Withdrawal, Withdrawals};
+use alloy_eips::{eip6110, eip7002::WITHDRAWAL_REQUEST_TYPE, eip7251, eip7685::Requests};
+use alloy_evm::eth::eip6110::parse_deposits_from_receipts;
 use alloy_primitives::U256;
-use alloy_primitives::{map::HashMap, Address, Bytes};
+use alloy_primitives::{map::HashMap, Address, Bytes, Log};
 use alloy_sol_macro::sol;
 use alloy_sol_types::SolCall;
 use reth_evm::{
@@ -232,3 +234,64 @@ where
 
     Ok((balance_increments, withdrawal_requests))
 }
+
+/// Applies everything that runs after the transactions of a block: the Prague EIP-7685 request
+/// calls, then the Gnosis post-block system calls.
+///
+/// Returns the block requests, with deposits parsed from `receipts`, and the balance increments
+/// that are left for the caller to credit.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn apply_post_execution_calls<SPEC, R>(
+    chain_spec: &SPEC,
+    block_rewards_contract: Address,
+    withdrawal_contract: Address,
+    block_timestamp: u64,
+    withdrawals: Option<&Withdrawals>,
+    coinbase: Address,
+    receipts: &[R],
+    evm: &mut impl Evm<DB: DatabaseCommit>,
+    system_caller: &mut SystemCaller<SPEC>,
+) -> Result<(Requests, HashMap<Address, u128>), BlockExecutionError>
+where
+    SPEC: EthExecutorSpec,
+    R: TxReceipt<Log = Log>,
+{
+    let mut requests = Requests::default();
+
+    if chain_spec.is_prague_active_at_timestamp(block_timestamp) {
+        // Collect all EIP-6110 deposits
+        let deposit_requests = parse_deposits_from_receipts(chain_spec, receipts)?;
+        if !deposit_requests.is_empty() {
+            requests.push_request_with_type(eip6110::DEPOSIT_REQUEST_TYPE, deposit_requests);
+        }
+
+        // Collect all EIP-7002 requests
+        let withdrawal_requests = system_caller.apply_withdrawal_requests_contract_call(evm)?;
+        if !withdrawal_requests.is_empty() {
+            requests.push_request_with_type(WITHDRAWAL_REQUEST_TYPE, withdrawal_requests);
+        }
+
+        // Collect all EIP-7251 requests
+        let consolidation_requests =
+            system_caller.apply_consolidation_requests_contract_call(evm)?;
+        if !consolidation_requests.is_empty() {
+            requests.push_request_with_type(
+                eip7251::CONSOLIDATION_REQUEST_TYPE,
+                consolidation_requests,
+            );
+        }
+    }
+
+    let (balance_increments, _) = apply_post_block_system_calls(
+        chain_spec,
+        block_rewards_contract,
+        withdrawal_contract,
+        block_timestamp,
+        withdrawals,
+        coinbase,
+        evm,
+        system_caller,
+    )?;
+
+    Ok((requests, balance_increments))
+}
@@ -0,0 +1,253 @@
+//! Launching the Gnosis node from other crates.
+//!
+//! [`GnosisNodeBuilder`] runs the same node as the `reth` binary, and lets embedders customize
+//! it before launch, e.g. to install their own ExExes:
+//!
+//! ```ignore
+//! use reth_gnosis::launch::{ExExContext, GnosisNodeBuilder};
+//!
+//! GnosisNodeBuilder::parse().launch_with(|builder, _| {
+//!     builder.install_exex("my-exex", |ctx| async move { Ok(my_exex(ctx)) })
+//! })
+//! ```
+//!
+//! Additional RPC modules go through [`GnosisNodeBuilder::extend_rpc_modules`], which keeps the
+//! `gnosis` namespace installed.
+
+use std::{ffi::OsString, fmt, sync::Arc};
+
+use clap::Parser;
+use reth::builder::{NodeBuilderWithComponents, WithLaunchContext};
+use reth_cli_commands::{common::EnvironmentArgs, node::NoArgs};
+use reth_db::DatabaseEnv;
+use reth_node_builder::{
+    rpc::{RethRpcAddOns, RpcContext},
+    Node, NodeAdapter, RethFullAdapter,
+};
+use reth_rpc_server_types::RethRpcModule;
+use reth_tasks::pool::BlockingTaskGuard;
+
+use crate::{
+    cli::Cli,
+    initialize::{
+        download_init_state::{CHIADO_DOWNLOAD_SPEC, GNOSIS_DOWNLOAD_SPEC},
+        import_and_ensure_state::download_and_import_init_state,
+    },
+    rpc::{GnosisApiServer, GnosisRpc, GNOSIS_RPC_MODULE},
+    spec::gnosis_spec::GnosisChainSpecParser,
+    GnosisAddOns, GnosisArgs, GnosisNode,
+};
+
+pub use reth_exex::{ExExContext, ExExEvent, ExExNotification};
+
+/// The full node types of a Gnosis node backed by the default database.
+pub type GnosisFullNodeTypes = RethFullAdapter<Arc<DatabaseEnv>, GnosisNode>;
+
+/// The node components of a Gnosis node backed by the default database.
+pub type GnosisNodeAdapter = NodeAdapter<GnosisFullNodeTypes>;
+
+/// The context handed to [`GnosisNodeBuilder::extend_rpc_modules`] hooks.
+pub type GnosisRpcContext<'a> = RpcContext<
+    'a,
+    GnosisNodeAdapter,
+    <GnosisAddOns<GnosisNodeAdapter> as RethRpcAddOns<GnosisNodeAdapter>>::EthApi,
+>;
+
+/// Hook installing additional RPC modules, see [`GnosisNodeBuilder::extend_rpc_modules`].
+type ExtendRpcModulesHook = Box<dyn FnOnce(GnosisRpcContext<'_>) -> eyre::Result<()> + Send>;
+
+/// The node builder handed to [`GnosisNodeBuilder::launch_with`], with all Gnosis components
+/// configured but not launched yet.
+pub type GnosisLaunchBuilder = WithLaunchContext<
+    NodeBuilderWithComponents<
+        GnosisFullNodeTypes,
+        <GnosisNode as Node<GnosisFullNodeTypes>>::ComponentsBuilder,
+        <GnosisNode as Node<GnosisFullNodeTypes>>::AddOns,
+    >,
+>;
+
+/// Arguments of the `node` command on top of reth's.
+#[derive(Debug, clap::Args)]
+pub struct GnosisNodeArgs<Ext: clap::Args + fmt::Debug = NoArgs> {
+    /// Gnosis node arguments.
+    #[command(flatten)]
+    pub gnosis: GnosisArgs,
+
+    /// Arguments of the embedder.
+    #[command(flatten)]
+    pub ext: Ext,
+}
+
+/// Builder to run the Gnosis node, including its CLI, from another crate.
+///
+/// `Ext` are additional CLI arguments of the embedder, passed to [`Self::launch_with`].
+pub struct GnosisNodeBuilder<Ext: clap::Args + fmt::Debug = NoArgs> {
+    cli: Cli<GnosisChainSpecParser, GnosisNodeArgs<Ext>>,
+    extend_rpc_modules: Option<ExtendRpcModulesHook>,
+}
+
+impl<Ext: clap::Args + fmt::Debug> fmt::Debug for GnosisNodeBuilder<Ext> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GnosisNodeBuilder")
+            .field("cli", &self.cli)
+            .field("extend_rpc_modules", &self.extend_rpc_modules.is_some())
+            .finish()
+    }
+}
+
+impl<Ext: clap::Args + fmt::Debug> GnosisNodeBuilder<Ext> {
+    /// Creates a builder from the arguments of the current process.
+    pub fn parse() -> Self {
+        Self::from_cli(Cli::parse())
+    }
+
+    /// Creates a builder from the given arguments, the first one being the binary name.
+    pub fn try_parse_from<I, T>(itr: I) -> Result<Self, clap::error::Error>
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<OsString> + Clone,
+    {
+        Cli::try_parse_from(itr).map(Self::from_cli)
+    }
+
+    /// Creates a builder from already parsed arguments.
+    pub const fn from_cli(cli: Cli<GnosisChainSpecParser, GnosisNodeArgs<Ext>>) -> Self {
+        Self {
+            cli,
+            extend_rpc_modules: None,
+        }
+    }
+
+    /// Sets a hook installing additional RPC modules, run after the `gnosis` namespace is
+    /// registered. A later call replaces the hook.
+    ///
+    /// Use this rather than `extend_rpc_modules` on the builder handed to
+    /// [`Self::launch_with`]: the node builder keeps a single such hook, so setting it there
+    /// replaces the one serving `gnosis_*`.
+    pub fn extend_rpc_modules<F>(mut self, hook: F) -> Self
+    where
+        F: FnOnce(GnosisRpcContext<'_>) -> eyre::Result<()> + Send + 'static,
+    {
+        self.extend_rpc_modules = Some(Box::new(hook));
+        self
+    }
+
+    /// Runs the configured command, launching the node as is for `reth node`.
+    pub fn launch(self) -> eyre::Result<()> {
+        self.launch_with(|builder, _| builder)
+    }
+
+    /// Runs the configured command, letting `configure` extend the node before it is launched
+    /// for `reth node`.
+    ///
+    /// On Gnosis and Chiado, the post-merge state is downloaded and imported first if the
+    /// datadir is empty.
+    ///
+    /// The `gnosis` RPC namespace is registered through the builder's `extend_rpc_modules`
+    /// hook. Calling `extend_rpc_modules` in `configure` replaces it, add RPC modules with
+    /// [`Self::extend_rpc_modules`] instead.
+    pub fn launch_with<F>(self, configure: F) -> eyre::Result<()>
+    where
+        F: FnOnce(GnosisLaunchBuilder, Ext) -> GnosisLaunchBuilder + Send + 'static,
+    {
+        let Self {
+            cli,
+            extend_rpc_modules,
+        } = self;
+        let _guard = cli.init_tracing();
+
+        // Fetch pre-merge state from a URL and load into the DB
+        if let reth::cli::Commands::Node(ref node_cmd) = cli.command {
+            let env = EnvironmentArgs::<GnosisChainSpecParser> {
+                datadir: node_cmd.datadir.clone(),
+                config: node_cmd.config.clone(),
+                chain: node_cmd.chain.clone(),
+                db: node_cmd.db,
+            };
+
+            match node_cmd.chain.chain().id() {
+                100 => download_and_import_init_state("gnosis", GNOSIS_DOWNLOAD_SPEC, env),
+                10200 => download_and_import_init_state("chiado", CHIADO_DOWNLOAD_SPEC, env),
+                _ => {} // For other network do not download state
+            }
+        }
+
+        cli.run(|builder, args| async move {
+            let GnosisNodeArgs { gnosis, ext } = args;
+            let builder = builder
+                .node(GnosisNode { args: gnosis })
+                .extend_rpc_modules(|ctx| {
+                    let gnosis_rpc = GnosisRpc::new(
+                        ctx.provider().clone(),
+                        ctx.node().evm_config().clone(),
+                        ctx.config().rpc.rpc_eth_proof_window,
+                        BlockingTaskGuard::new(ctx.config().rpc.rpc_max_tracing_requests),
+                    );
+                    // Only served where `gnosis` is listed in `--http.api`/`--ws.api`
+                    ctx.modules.merge_if_module_configured(
+                        RethRpcModule::Other(GNOSIS_RPC_MODULE.to_string()),
+                        gnosis_rpc.into_rpc(),
+                    )?;
+                    match extend_rpc_modules {
+                        Some(hook) => hook(ctx),
+                        None => Ok(()),
+                    }
+                });
+            let handle = configure(builder, ext)
+                .launch_with_debug_capabilities()
+                .await?;
+            handle.node_exit_future.await
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use reth::cli::Commands;
+
+    use super::*;
+
+    #[test]
+    fn parses_gnosis_and_embedder_args() {
+        #[derive(Debug, clap::Args)]
+        struct ExtArgs {
+            #[arg(long)]
+            my_flag: bool,
+        }
+
+        let builder = GnosisNodeBuilder::<ExtArgs>::try_parse_from([
+            "reth",
+            "node",
+            "--gnosis.sample-arg",
+            "sample",
+            "--my-flag",
+        ])
+        .unwrap();
+        let Commands::Node(node) = builder.cli.command else {
+            panic!("expected the node command");
+        };
+        assert_eq!(node.ext.gnosis.sample_arg.as_deref(), Some("sample"));
+        assert!(node.ext.ext.my_flag);
+    }
+
+    #[test]
+    fn install_exex_keeps_the_launch_builder_type() {
+        // As in the README: `launch_with` needs the configured builder back as is
+        let _configure = |builder: GnosisLaunchBuilder, _: NoArgs| -> GnosisLaunchBuilder {
+            builder.install_exex("x", |_ctx| async move { Ok(async { Ok(()) }) })
+        };
+    }
+
+    #[test]
+    fn extend_rpc_modules_is_kept_until_launch() {
+        let builder = GnosisNodeBuilder::<NoArgs>::try_parse_from(["reth", "node"]).unwrap();
+        assert!(builder.extend_rpc_modules.is_none());
+
+        let builder = builder.extend_rpc_modules(|ctx: GnosisRpcContext<'_>| {
+            ctx.modules
+                .merge_configured(jsonrpsee::RpcModule::new(()))?;
+            Ok(())
+        });
+        assert!(builder.extend_rpc_modules.is_some());
+    }
+}
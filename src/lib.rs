@@ -39,12 +39,13 @@ mod evm;
 mod evm_config;
 mod gnosis;
 pub mod initialize;
+pub mod launch;
 mod network;
 mod payload;
 mod payload_builder;
 mod pool;
-mod primitives;
-mod rpc;
+pub mod primitives;
+pub mod rpc;
 pub mod spec;
 mod testing;
 
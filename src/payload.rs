@@ -1,8 +1,12 @@
-use std::sync::Arc;
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
 use alloy_consensus::Transaction;
 use alloy_eips::eip7685::Requests;
 use alloy_rlp::Encodable;
+use metrics::histogram;
 use reth::{
     consensus_common::validation::MAX_RLP_BLOCK_SIZE,
     rpc::types::engine::{
@@ -217,6 +221,8 @@ where
 
     let is_osaka = chain_spec.is_osaka_active_at_timestamp(attributes.timestamp);
 
+    let mut timings = PayloadBuildTimings::default();
+    let mut selection_started = Instant::now();
     while let Some(pool_tx) = best_txs.next() {
         // ensure we still have capacity for this transaction
         if cumulative_gas_used + pool_tx.gas_limit() > block_gas_limit {
@@ -310,7 +316,13 @@ where
             };
         }
 
-        let gas_used = match builder.execute_transaction(tx.clone()) {
+        timings.tx_selection += selection_started.elapsed();
+        let execution_started = Instant::now();
+        let execution_result = builder.execute_transaction(tx.clone());
+        timings.execution += execution_started.elapsed();
+        selection_started = Instant::now();
+
+        let gas_used = match execution_result {
             Ok(gas_used) => gas_used,
             Err(BlockExecutionError::Validation(BlockValidationError::InvalidTx {
                 error, ..
@@ -359,6 +371,7 @@ where
             blob_sidecars.push_sidecar_variant(sidecar.as_ref().clone());
         }
     }
+    timings.tx_selection += selection_started.elapsed();
 
     // check if we have a better block
     if !is_better_payload(best_payload.as_ref(), total_fees) {
@@ -372,11 +385,13 @@ where
         });
     }
 
+    let sealing_started = Instant::now();
     let BlockBuilderOutcome {
         execution_result,
         block,
         ..
     } = builder.finish(&state_provider)?;
+    timings.sealing = sealing_started.elapsed();
 
     let requests = chain_spec
         .is_prague_active_at_timestamp(attributes.timestamp)
@@ -384,6 +399,7 @@ where
 
     let sealed_block = Arc::new(block.sealed_block().clone());
     debug!(target: "payload_builder", id=%attributes.id, sealed_block_header = ?sealed_block.sealed_header(), "sealed built block");
+    timings.report(attributes.id, &sealed_block);
 
     let payload = GnosisBuiltPayload::new(attributes.id, sealed_block, total_fees, requests)
         // add blob sidecars from the executed txs
@@ -395,6 +411,47 @@ where
     })
 }
 
+/// Time spent in each phase of building a single payload.
+///
+/// Gnosis has 5 second slots, so these are reported for every built block to help tune the
+/// builder. Each phase is recorded in the `gnosis_payload_build_duration_seconds` histogram,
+/// labelled by `phase`, so it is kept by the metrics endpoint. A debug event with the same
+/// timings is also emitted under the `payload_builder::timings` target.
+#[derive(Debug, Default, Clone, Copy)]
+struct PayloadBuildTimings {
+    /// Pulling transactions from the pool and checking that they fit in the block.
+    tx_selection: Duration,
+    /// Executing the selected transactions.
+    execution: Duration,
+    /// Post-block system calls, state root computation and sealing.
+    sealing: Duration,
+}
+
+impl PayloadBuildTimings {
+    fn report(&self, id: PayloadId, block: &SealedBlock<RethBlock>) {
+        for (phase, duration) in [
+            ("tx_selection", self.tx_selection),
+            ("execution", self.execution),
+            ("sealing", self.sealing),
+        ] {
+            histogram!("gnosis_payload_build_duration_seconds", "phase" => phase).record(duration);
+        }
+
+        debug!(
+            target: "payload_builder::timings",
+            %id,
+            number = block.number,
+            txs = block.body().transactions.len(),
+            gas_used = block.gas_used,
+            tx_selection = ?self.tx_selection,
+            execution = ?self.execution,
+            sealing = ?self.sealing,
+            total = ?(self.tx_selection + self.execution + self.sealing),
+            "payload build timings"
+        );
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct GnosisBuiltPayload {
     /// Identifier of the payload
@@ -1,15 +1,85 @@
+use std::time::Duration;
+
+use alloy_consensus::BlockHeader;
+use futures_util::StreamExt;
+use metrics::counter;
 use reth_node_builder::{
     components::PoolBuilder,
     node::{FullNodeTypes, NodeTypes},
     BuilderContext,
 };
-use reth_provider::CanonStateSubscriptions;
+use reth_provider::{CanonStateSubscriptions, HeaderProvider};
 use reth_transaction_pool::{
-    blobstore::DiskFileBlobStore, EthTransactionPool, TransactionValidationTaskExecutor,
+    blobstore::DiskFileBlobStore, maintain::MaintainPoolConfig, EthTransactionPool,
+    FullTransactionEvent, PoolConfig, SubPoolLimit, TransactionPool,
+    TransactionValidationTaskExecutor,
 };
 use tracing::info;
 
 use crate::{primitives::GnosisNodePrimitives, spec::gnosis_spec::GnosisChainSpec};
+
+/// Ethereum mainnet block time the upstream pool defaults are calibrated for.
+const MAINNET_BLOCK_TIME: Duration = Duration::from_secs(12);
+
+/// Gnosis block time.
+const GNOSIS_BLOCK_TIME: Duration = Duration::from_secs(5);
+
+/// Scales a quantity upstream calibrated for mainnet blocks to the same number of Gnosis blocks.
+const fn scale_to_gnosis_blocks(value: u64) -> u64 {
+    value * GNOSIS_BLOCK_TIME.as_secs() / MAINNET_BLOCK_TIME.as_secs()
+}
+
+/// Sub-pool limit for non-executable transactions: the upstream limit scaled like the queued
+/// lifetime, so the sub-pool holds about as many blocks worth of transactions as on mainnet.
+fn gnosis_sub_pool_limit(upstream: SubPoolLimit) -> SubPoolLimit {
+    SubPoolLimit {
+        max_txs: scale_to_gnosis_blocks(upstream.max_txs as u64) as usize,
+        max_size: scale_to_gnosis_blocks(upstream.max_size as u64) as usize,
+    }
+}
+
+/// Replaces the pool settings upstream calibrated for 12s mainnet blocks with ones for 5s Gnosis
+/// blocks, unless they were changed with the `--txpool.*` flags.
+///
+/// Non-executable transactions are kept for the same number of blocks as on mainnet: queued
+/// transactions are evicted after 75 minutes instead of 3 hours, and the queued and basefee
+/// sub-pools are shrunk by the same ratio. The pending sub-pool is left as is, it drains with every
+/// block. A flag set to its upstream default can't be told apart from an unset one, so it gets the
+/// Gnosis value too.
+fn apply_gnosis_pool_defaults(pool_config: &mut PoolConfig) {
+    let upstream = PoolConfig::default();
+    if pool_config.max_queued_lifetime == upstream.max_queued_lifetime {
+        pool_config.max_queued_lifetime = Duration::from_secs(scale_to_gnosis_blocks(
+            upstream.max_queued_lifetime.as_secs(),
+        ));
+    }
+    if pool_config.queued_limit == upstream.queued_limit {
+        pool_config.queued_limit = gnosis_sub_pool_limit(upstream.queued_limit);
+    }
+    if pool_config.basefee_limit == upstream.basefee_limit {
+        pool_config.basefee_limit = gnosis_sub_pool_limit(upstream.basefee_limit);
+    }
+}
+
+/// Counts transactions entering, leaving and being replaced in the pool.
+///
+/// Exposed as the `gnosis_txpool_churn` counter, labeled by event.
+async fn track_pool_churn<Pool: TransactionPool>(pool: Pool) {
+    let mut events = pool.all_transactions_event_listener();
+    while let Some(event) = events.next().await {
+        let event = match event {
+            FullTransactionEvent::Pending(..) => "pending",
+            FullTransactionEvent::Queued(..) => "queued",
+            FullTransactionEvent::Mined { .. } => "mined",
+            FullTransactionEvent::Replaced { .. } => "replaced",
+            FullTransactionEvent::Discarded(..) => "discarded",
+            FullTransactionEvent::Invalid(..) => "invalid",
+            _ => continue,
+        };
+        counter!("gnosis_txpool_churn", "event" => event).increment(1);
+    }
+}
+
 #[derive(Debug, Clone, Default)]
 #[non_exhaustive]
 pub struct GnosisPoolBuilder {}
@@ -23,10 +93,18 @@ where
 
     async fn build_pool(self, ctx: &BuilderContext<Node>) -> eyre::Result<Self::Pool> {
         let data_dir = ctx.config().datadir();
-        let pool_config = ctx.pool_config();
+        let mut pool_config = ctx.pool_config();
+        apply_gnosis_pool_defaults(&mut pool_config);
+        // Until the first new head, the validator checks against the gas limit of the current
+        // head rather than mainnet's
+        let head_gas_limit = ctx
+            .provider()
+            .header_by_number(ctx.head().number)?
+            .map(|header| header.gas_limit());
         let blob_store = DiskFileBlobStore::open(data_dir.blobstore(), Default::default())?;
         let validator = TransactionValidationTaskExecutor::eth_builder(ctx.provider().clone())
             .with_head_timestamp(ctx.head().timestamp)
+            .set_block_gas_limit(head_gas_limit.unwrap_or(pool_config.gas_limit))
             .kzg_settings(ctx.kzg_settings()?)
             .with_local_transactions_config(pool_config.local_transactions_config.clone())
             .with_additional_tasks(ctx.config().txpool.additional_validation_tasks)
@@ -40,6 +118,11 @@ where
         // spawn txpool maintenance task
         {
             let pool = transaction_pool.clone();
+            let maintain_config = MaintainPoolConfig {
+                max_tx_lifetime: pool.config().max_queued_lifetime,
+                no_local_exemptions: pool.config().local_transactions_config.no_exemptions,
+                ..Default::default()
+            };
             let chain_events = ctx.provider().canonical_state_stream();
             let client = ctx.provider().clone();
             let transactions_backup_config =
@@ -65,11 +148,59 @@ where
                     pool,
                     chain_events,
                     ctx.task_executor().clone(),
-                    Default::default(),
+                    maintain_config,
                 ),
             );
+
+            // spawn the churn metrics task
+            ctx.task_executor()
+                .spawn(track_pool_churn(transaction_pool.clone()));
         }
 
         Ok(transaction_pool)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn defaults_scaled_to_gnosis_blocks() {
+        let upstream = PoolConfig::default();
+        let mut pool_config = PoolConfig::default();
+        apply_gnosis_pool_defaults(&mut pool_config);
+
+        assert_eq!(
+            pool_config.max_queued_lifetime,
+            Duration::from_secs(75 * 60)
+        );
+        let scaled = SubPoolLimit {
+            max_txs: upstream.queued_limit.max_txs * 5 / 12,
+            max_size: upstream.queued_limit.max_size * 5 / 12,
+        };
+        assert_eq!(pool_config.queued_limit, scaled);
+        assert_eq!(pool_config.basefee_limit, scaled);
+        assert_eq!(pool_config.pending_limit, upstream.pending_limit);
+    }
+
+    #[test]
+    fn txpool_flags_are_kept() {
+        let mut pool_config = PoolConfig {
+            max_queued_lifetime: Duration::from_secs(60),
+            queued_limit: SubPoolLimit {
+                max_txs: 100,
+                max_size: 1_000,
+            },
+            ..Default::default()
+        };
+        let expected = pool_config.clone();
+        apply_gnosis_pool_defaults(&mut pool_config);
+
+        assert_eq!(
+            pool_config.max_queued_lifetime,
+            expected.max_queued_lifetime
+        );
+        assert_eq!(pool_config.queued_limit, expected.queued_limit);
+    }
+}
@@ -1,4 +1,5 @@
 use alloy_consensus::TxEip4844;
+use reth_primitives_traits::{RecoveredBlock, SealedBlock};
 
 pub type TransactionSigned = alloy_consensus::EthereumTxEnvelope<TxEip4844>;
 
@@ -9,3 +10,13 @@ pub type GnosisBlock = alloy_consensus::Block<TransactionSigned, GnosisHeader>;
 
 /// The body type of this node
 pub type BlockBody = alloy_consensus::BlockBody<TransactionSigned, GnosisHeader>;
+
+/// A [`GnosisBlock`] sealed with its hash, see [`Block::seal_slow`]
+///
+/// [`Block::seal_slow`]: reth_primitives_traits::Block::seal_slow
+pub type GnosisSealedBlock = SealedBlock<GnosisBlock>;
+
+/// A [`GnosisBlock`] with its hash and recovered senders, see [`Block::try_into_recovered`]
+///
+/// [`Block::try_into_recovered`]: reth_primitives_traits::Block::try_into_recovered
+pub type GnosisRecoveredBlock = RecoveredBlock<GnosisBlock>;
@@ -0,0 +1,303 @@
+use alloy_consensus::{
+    constants::{EMPTY_OMMER_ROOT_HASH, EMPTY_ROOT_HASH},
+    proofs::calculate_withdrawals_root,
+};
+use alloy_eips::eip4895::Withdrawal;
+use alloy_primitives::{Address, Bloom, Bytes, B256, B64, U256};
+use reth_primitives_traits::SealedHeader;
+
+use super::block::GnosisHeader;
+
+/// Builder for [`GnosisHeader`]s, for tests and tools that need to craft headers by hand.
+///
+/// Starts from an empty post-merge header: empty ommers, transactions and receipts, zero
+/// difficulty and nonce. Pre-merge AuRa headers carry seal fields that [`GnosisHeader`] cannot
+/// represent, so they cannot be built (or hashed) with it.
+#[derive(Debug, Clone)]
+pub struct GnosisHeaderBuilder {
+    header: GnosisHeader,
+}
+
+impl Default for GnosisHeaderBuilder {
+    fn default() -> Self {
+        Self {
+            header: GnosisHeader {
+                ommers_hash: EMPTY_OMMER_ROOT_HASH,
+                state_root: EMPTY_ROOT_HASH,
+                transactions_root: EMPTY_ROOT_HASH,
+                receipts_root: EMPTY_ROOT_HASH,
+                ..Default::default()
+            },
+        }
+    }
+}
+
+impl GnosisHeaderBuilder {
+    /// Creates a new builder for an empty post-merge header.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the parent hash.
+    pub const fn parent_hash(mut self, parent_hash: B256) -> Self {
+        self.header.parent_hash = parent_hash;
+        self
+    }
+
+    /// Sets the fee recipient.
+    pub const fn beneficiary(mut self, beneficiary: Address) -> Self {
+        self.header.beneficiary = beneficiary;
+        self
+    }
+
+    /// Sets the state root.
+    pub const fn state_root(mut self, state_root: B256) -> Self {
+        self.header.state_root = state_root;
+        self
+    }
+
+    /// Sets the transactions root.
+    pub const fn transactions_root(mut self, transactions_root: B256) -> Self {
+        self.header.transactions_root = transactions_root;
+        self
+    }
+
+    /// Sets the receipts root.
+    pub const fn receipts_root(mut self, receipts_root: B256) -> Self {
+        self.header.receipts_root = receipts_root;
+        self
+    }
+
+    /// Sets the logs bloom.
+    pub const fn logs_bloom(mut self, logs_bloom: Bloom) -> Self {
+        self.header.logs_bloom = logs_bloom;
+        self
+    }
+
+    /// Sets the difficulty, only non-zero before the merge.
+    pub const fn difficulty(mut self, difficulty: U256) -> Self {
+        self.header.difficulty = difficulty;
+        self
+    }
+
+    /// Sets the block number.
+    pub const fn number(mut self, number: u64) -> Self {
+        self.header.number = number;
+        self
+    }
+
+    /// Sets the gas limit.
+    pub const fn gas_limit(mut self, gas_limit: u64) -> Self {
+        self.header.gas_limit = gas_limit;
+        self
+    }
+
+    /// Sets the gas used.
+    pub const fn gas_used(mut self, gas_used: u64) -> Self {
+        self.header.gas_used = gas_used;
+        self
+    }
+
+    /// Sets the timestamp.
+    pub const fn timestamp(mut self, timestamp: u64) -> Self {
+        self.header.timestamp = timestamp;
+        self
+    }
+
+    /// Sets the extra data.
+    pub fn extra_data(mut self, extra_data: Bytes) -> Self {
+        self.header.extra_data = extra_data;
+        self
+    }
+
+    /// Sets the mix hash, which holds `prevRandao` after the merge.
+    pub const fn mix_hash(mut self, mix_hash: B256) -> Self {
+        self.header.mix_hash = mix_hash;
+        self
+    }
+
+    /// Sets the nonce.
+    pub const fn nonce(mut self, nonce: B64) -> Self {
+        self.header.nonce = nonce;
+        self
+    }
+
+    /// Sets the base fee, present since London.
+    pub const fn base_fee_per_gas(mut self, base_fee_per_gas: u64) -> Self {
+        self.header.base_fee_per_gas = Some(base_fee_per_gas);
+        self
+    }
+
+    /// Sets the withdrawals root, present since Shanghai.
+    pub const fn withdrawals_root(mut self, withdrawals_root: B256) -> Self {
+        self.header.withdrawals_root = Some(withdrawals_root);
+        self
+    }
+
+    /// Sets the withdrawals root to the root of the given withdrawals.
+    pub fn withdrawals(self, withdrawals: &[Withdrawal]) -> Self {
+        self.withdrawals_root(calculate_withdrawals_root(withdrawals))
+    }
+
+    /// Sets the blob gas used, present since Cancun.
+    pub const fn blob_gas_used(mut self, blob_gas_used: u64) -> Self {
+        self.header.blob_gas_used = Some(blob_gas_used);
+        self
+    }
+
+    /// Sets the excess blob gas, present since Cancun.
+    pub const fn excess_blob_gas(mut self, excess_blob_gas: u64) -> Self {
+        self.header.excess_blob_gas = Some(excess_blob_gas);
+        self
+    }
+
+    /// Sets the parent beacon block root, present since Cancun.
+    pub const fn parent_beacon_block_root(mut self, parent_beacon_block_root: B256) -> Self {
+        self.header.parent_beacon_block_root = Some(parent_beacon_block_root);
+        self
+    }
+
+    /// Sets the requests hash, present since Prague.
+    pub const fn requests_hash(mut self, requests_hash: B256) -> Self {
+        self.header.requests_hash = Some(requests_hash);
+        self
+    }
+
+    /// Returns the built header.
+    pub fn build(self) -> GnosisHeader {
+        self.header
+    }
+
+    /// Returns the built header, sealed with its computed hash.
+    pub fn seal_slow(self) -> SealedHeader<GnosisHeader> {
+        SealedHeader::seal_slow(self.header)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_eips::eip7685::EMPTY_REQUESTS_HASH;
+    use alloy_primitives::{address, b256, bytes};
+    use alloy_rlp::{Decodable, Encodable};
+
+    // Blocks 1 and 2 of the local devnet fixtures in `scripts/blocks` and `scripts/eip4895_blocks`
+    fn paris_header() -> GnosisHeader {
+        GnosisHeaderBuilder::new()
+            .parent_hash(b256!(
+                "574c165efc3205be13affbd3bc165df6a6a023e21d142dd4f4c682d2aa3a054e"
+            ))
+            .state_root(b256!(
+                "e65e8c7050e23e1f25c7f56416f899b1ce15ec130ee0460b12529b92b4a8ba81"
+            ))
+            .number(1)
+            .gas_limit(0x989680)
+            .timestamp(0x6553f101)
+            .extra_data(bytes!("4e65746865726d696e64"))
+            .base_fee_per_gas(0x342770c0)
+            .build()
+    }
+
+    fn shanghai_header() -> GnosisHeader {
+        GnosisHeaderBuilder::new()
+            .parent_hash(b256!(
+                "e4dc10cdb7b654e3dc9217484a5348d0f951b7cf240ee635a69b8c34596accea"
+            ))
+            .state_root(b256!(
+                "03f2eb7ed1fd38d6e907d6671988c098a5a0d1d289cf2499633d0a402314b274"
+            ))
+            .number(2)
+            .gas_limit(0x989680)
+            .timestamp(0x65971a61)
+            .extra_data(bytes!("4e65746865726d696e64"))
+            .base_fee_per_gas(0x31a2a1de)
+            .withdrawals(&[Withdrawal {
+                index: 0xf0,
+                validator_index: 0xf0,
+                address: address!("38e3e7aca6762e296f659fcb4e460a3a621dcd3d"),
+                amount: 0x10000000000,
+            }])
+            .build()
+    }
+
+    // Block 2 of the devnet fixtures in `scripts/eip4844_blocks_cancun` and
+    // `scripts/eip4844_blocks_pectra`, carrying a blob transaction
+    fn cancun_header() -> GnosisHeader {
+        GnosisHeaderBuilder::new()
+            .parent_hash(b256!(
+                "407067b07e7072c608d43924a4a584d832ade429cc340eaa7509525492d5d495"
+            ))
+            .state_root(b256!(
+                "87b26d34e4b21b36ce1922ca2df643ebc6f7c62e3e370d62b283ef5106184fa2"
+            ))
+            .transactions_root(b256!(
+                "3cae1cee8946fb78b140cb98524ef36e4b0b21a27d5d2ea6bb5ba93cc5b977e8"
+            ))
+            .receipts_root(b256!(
+                "eaa8c40899a61ae59615cf9985f5e2194f8fd2b57d273be63bde6733e89b12ab"
+            ))
+            .number(2)
+            .gas_limit(0x989680)
+            .gas_used(0x5208)
+            .timestamp(0x6e44c2e4)
+            .extra_data(bytes!("4e65746865726d696e64"))
+            .base_fee_per_gas(0x2dbb3d14)
+            .withdrawals(&[])
+            .blob_gas_used(0x20000)
+            .excess_blob_gas(0)
+            .parent_beacon_block_root(b256!(
+                "1100000000000000000000000000000000000000000000000000000000000000"
+            ))
+            .build()
+    }
+
+    fn prague_header() -> GnosisHeader {
+        GnosisHeader {
+            state_root: b256!("513f7ce688de9dfbb80d2d9f7dd8f9b88a3480520de2fb6d2c256eae913bd38e"),
+            timestamp: 0x743aa3b2,
+            requests_hash: Some(EMPTY_REQUESTS_HASH),
+            ..cancun_header()
+        }
+    }
+
+    #[test]
+    fn header_hash_golden() {
+        assert_eq!(
+            paris_header().hash_slow(),
+            b256!("9636978fddf349fc5e92e85b2080d99b5c3e44c745359a77e603e7f1a76f440c")
+        );
+        assert_eq!(
+            shanghai_header().hash_slow(),
+            b256!("283bffaaddd1d449572838ae60e8fcea891fee29c8f5e76b5efd5c99fb4107da")
+        );
+        assert_eq!(
+            cancun_header().hash_slow(),
+            b256!("593ed0f72c6a230f92b176189d5f4aa873ff440abc47b1e69635656275349051")
+        );
+        assert_eq!(
+            prague_header().hash_slow(),
+            b256!("a97b1f9b65261d4bd17c9109253112a1cb870012b6cf2167d5c41ef6ccc98e16")
+        );
+    }
+
+    #[test]
+    fn header_rlp_and_serde_roundtrip() {
+        for header in [
+            paris_header(),
+            shanghai_header(),
+            cancun_header(),
+            prague_header(),
+        ] {
+            let mut encoded = Vec::new();
+            header.encode(&mut encoded);
+            assert_eq!(encoded.len(), header.length());
+            assert_eq!(
+                GnosisHeader::decode(&mut encoded.as_slice()).unwrap(),
+                header
+            );
+
+            let json = serde_json::to_string(&header).unwrap();
+            assert_eq!(serde_json::from_str::<GnosisHeader>(&json).unwrap(), header);
+        }
+    }
+}
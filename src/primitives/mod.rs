@@ -5,6 +5,7 @@ use block::{BlockBody, GnosisBlock, TransactionSigned};
 use reth_primitives::{NodePrimitives, Receipt};
 
 pub mod block;
+pub mod header;
 
 #[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub struct GnosisNodePrimitives;
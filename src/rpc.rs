@@ -1,8 +1,46 @@
 // NOTE: Needed for AddOns
 
+use std::collections::BTreeMap;
+
+use alloy_consensus::{BlockHeader, Transaction};
+use alloy_eips::BlockId;
+use alloy_primitives::{Address, B256, U256};
+use alloy_rpc_types_eth::EIP1186AccountProofResponse;
+use alloy_serde::JsonStorageKey;
+use alloy_sol_macro::sol;
+use alloy_sol_types::SolCall;
+use jsonrpsee::{
+    core::{async_trait, RpcResult},
+    proc_macros::rpc,
+    types::{
+        error::{INTERNAL_ERROR_CODE, INVALID_PARAMS_CODE},
+        ErrorObjectOwned,
+    },
+};
+use reth_chainspec::{EthChainSpec, EthereumHardforks};
+use reth_evm::{
+    block::{BlockExecutor, SystemCaller},
+    eth::spec::EthExecutorSpec,
+    ConfigureEvm, Evm,
+};
+use reth_primitives::Receipt;
+use reth_provider::{
+    BlockReaderIdExt, ChainSpecProvider, StateProofProvider, StateProviderFactory,
+    TransactionVariant,
+};
+use reth_revm::{database::StateProviderDatabase, db::State};
 use reth_rpc::RpcTypes;
+use reth_tasks::pool::BlockingTaskGuard;
+use reth_trie::TrieInput;
+use revm::context::result::{ExecutionResult, ResultAndState};
+use serde::{Deserialize, Serialize};
 
-use crate::primitives::block::GnosisHeader;
+use crate::{
+    evm_config::GnosisEvmConfig,
+    gnosis::apply_post_execution_calls,
+    primitives::block::{GnosisBlock, GnosisHeader, GnosisRecoveredBlock, TransactionSigned},
+    spec::gnosis_spec::GnosisChainSpec,
+};
 
 /// The gnosis RPC network types
 #[derive(Debug, Copy, Default, Clone)]
@@ -15,3 +53,723 @@ impl RpcTypes for GnosisNetwork {
     type TransactionRequest = alloy_rpc_types_eth::transaction::TransactionRequest;
     type TransactionResponse = alloy_rpc_types_eth::Transaction;
 }
+
+// Withdrawals that could not be transferred are left for their recipient to claim from the deposit
+// contract, see <https://github.com/gnosischain/specs/blob/master/execution/withdrawals.md>
+sol!(
+    function withdrawableAmount(address) external view returns (uint256);
+);
+
+/// Name of the RPC module to list in `--http.api` or `--ws.api` to serve the `gnosis`
+/// namespace.
+pub const GNOSIS_RPC_MODULE: &str = "gnosis";
+
+/// Maximum number of accounts a single `gnosis_getProofs` call may ask for.
+pub const MAX_PROOF_REQUESTS: usize = 256;
+
+/// Maximum number of storage slots, over all accounts, a single `gnosis_getProofs` call may ask
+/// for.
+pub const MAX_PROOF_STORAGE_KEYS: usize = 1024;
+
+/// An account, and the storage slots of it, to prove.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProofRequest {
+    /// The account to prove.
+    pub address: Address,
+    /// The storage slots to prove.
+    #[serde(default)]
+    pub storage_keys: Vec<JsonStorageKey>,
+}
+
+/// Rewards paid out by a block, per the Gnosis reward scheme.
+///
+/// Unlike on Ethereum the base fee is not burned but credited to the EIP-1559 fee collector, and
+/// the block rewards contract mints native tokens (e.g. for the bridges) at the end of the block.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockRewards {
+    /// Number of the block.
+    pub block_number: u64,
+    /// Hash of the block.
+    pub block_hash: B256,
+    /// The EIP-1559 fee collector.
+    pub fee_collector: Address,
+    /// Base fees, and since Prague blob fees, credited to the fee collector.
+    pub fee_collector_reward: U256,
+    /// The block's fee recipient.
+    pub validator: Address,
+    /// Priority fees paid to the fee recipient.
+    pub validator_reward: U256,
+    /// Native tokens minted by the block rewards contract, per receiver.
+    pub block_rewards_contract_mints: BTreeMap<Address, U256>,
+}
+
+/// Gnosis specific RPC methods, served under the `gnosis` namespace.
+#[rpc(server, namespace = "gnosis")]
+pub trait GnosisApi {
+    /// Returns the account and storage proofs of several accounts at the same block.
+    ///
+    /// Batch variant of `eth_getProof`, see <https://eips.ethereum.org/EIPS/eip-1186>. Like it,
+    /// only blocks within `--rpc.eth-proof-window` of the tip can be proven.
+    #[method(name = "getProofs")]
+    async fn get_proofs(
+        &self,
+        requests: Vec<ProofRequest>,
+        block_id: Option<BlockId>,
+    ) -> RpcResult<Vec<EIP1186AccountProofResponse>>;
+
+    /// Returns the rewards paid out by the given block.
+    ///
+    /// The block rewards contract mints are only known after executing the block, so the block
+    /// is re-executed on top of its parent state.
+    #[method(name = "getBlockRewards")]
+    async fn get_block_rewards(&self, block_id: BlockId) -> RpcResult<Option<BlockRewards>>;
+
+    /// Returns the amount of GNO withdrawals the given address can claim from the deposit
+    /// contract, i.e. withdrawals whose transfer failed during the post-block system call.
+    #[method(name = "getClaimableWithdrawals")]
+    async fn get_claimable_withdrawals(
+        &self,
+        address: Address,
+        block_id: Option<BlockId>,
+    ) -> RpcResult<Option<U256>>;
+}
+
+/// Implementation of [`GnosisApiServer`] on top of the node's provider.
+#[derive(Debug, Clone)]
+pub struct GnosisRpc<Provider> {
+    provider: Provider,
+    evm_config: GnosisEvmConfig,
+    /// How many blocks behind the tip proofs can be requested for.
+    proof_window: u64,
+    /// Limits the number of expensive calls running at once, like reth's trace and debug APIs.
+    blocking_task_guard: BlockingTaskGuard,
+}
+
+impl<Provider> GnosisRpc<Provider> {
+    /// Creates a new [`GnosisRpc`] reading from the given provider and executing blocks with the
+    /// given EVM config.
+    ///
+    /// `proof_window` and `blocking_task_guard` should follow `--rpc.eth-proof-window` and
+    /// `--rpc.max-tracing-requests`.
+    pub const fn new(
+        provider: Provider,
+        evm_config: GnosisEvmConfig,
+        proof_window: u64,
+        blocking_task_guard: BlockingTaskGuard,
+    ) -> Self {
+        Self {
+            provider,
+            evm_config,
+            proof_window,
+            blocking_task_guard,
+        }
+    }
+}
+
+impl<Provider> GnosisRpc<Provider>
+where
+    Provider: StateProviderFactory
+        + BlockReaderIdExt<Block = GnosisBlock, Header = GnosisHeader, Receipt = Receipt>
+        + ChainSpecProvider<ChainSpec = GnosisChainSpec>,
+{
+    fn block_rewards(&self, block_id: BlockId) -> eyre::Result<Option<BlockRewards>> {
+        let Some(number) = self.provider.block_number_for_id(block_id)? else {
+            return Ok(None);
+        };
+        let Some(block) = self
+            .provider
+            .recovered_block(number.into(), TransactionVariant::WithHash)?
+        else {
+            return Ok(None);
+        };
+        let receipts = self
+            .provider
+            .receipts_by_block(number.into())?
+            .ok_or_else(|| eyre::eyre!("missing receipts for block {number}"))?;
+
+        let chain_spec = self.provider.chain_spec();
+        // Blob fees are credited to the fee collector since Prague, and burned before
+        let blob_fee = if chain_spec.is_prague_active_at_timestamp(block.timestamp()) {
+            chain_spec
+                .blob_params_at_timestamp(block.timestamp())
+                .and_then(|params| block.blob_fee(params))
+                .unwrap_or_default()
+        } else {
+            0
+        };
+        let (fee_collector_reward, validator_reward) = split_fees(
+            block.base_fee_per_gas().unwrap_or_default(),
+            block.gas_used(),
+            blob_fee,
+            block.blob_gas_used().unwrap_or_default(),
+            block.body().transactions.iter().zip(&receipts),
+        );
+
+        Ok(Some(BlockRewards {
+            block_number: number,
+            block_hash: block.hash(),
+            fee_collector: self
+                .evm_config
+                .executor_factory
+                .evm_factory()
+                .fee_collector_address,
+            fee_collector_reward,
+            validator: block.beneficiary(),
+            validator_reward,
+            block_rewards_contract_mints: self.block_rewards_contract_mints(&block)?,
+        }))
+    }
+
+    /// Re-executes the given block and returns what the block rewards contract minted.
+    fn block_rewards_contract_mints(
+        &self,
+        block: &GnosisRecoveredBlock,
+    ) -> eyre::Result<BTreeMap<Address, U256>> {
+        let chain_spec = self.provider.chain_spec();
+        let deposit_contract = chain_spec
+            .deposit_contract_address()
+            .ok_or_else(|| eyre::eyre!("deposit contract address is not set"))?;
+
+        if block.number() == 0 {
+            eyre::bail!("the genesis block is not executed, it has no rewards contract mints");
+        }
+        // Missing below the imported post-merge state, or once pruned
+        let state = self
+            .provider
+            .history_by_block_hash(block.parent_hash())
+            .map_err(|err| {
+                eyre::eyre!(
+                    "state before block {} is not available: {err}",
+                    block.number()
+                )
+            })?;
+        let mut db = State::builder()
+            .with_database(StateProviderDatabase::new(&state))
+            .with_bundle_update()
+            .build();
+
+        let mut executor = self
+            .evm_config
+            .executor_for_block(&mut db, block.sealed_block());
+        executor.apply_pre_execution_changes()?;
+        for tx in block.transactions_recovered() {
+            executor.execute_transaction(tx)?;
+        }
+
+        // Same post-block calls as `GnosisBlockExecutor::finish`, keeping the balance increments.
+        // The requests are dropped, so no receipts are passed: deposits are only parsed from them
+        // and don't change state
+        let (_, balance_increments) = apply_post_execution_calls(
+            &chain_spec,
+            self.evm_config.executor_factory.block_rewards_address(),
+            deposit_contract,
+            block.timestamp(),
+            block.body().withdrawals.as_ref(),
+            block.beneficiary(),
+            &[] as &[Receipt],
+            executor.evm_mut(),
+            &mut SystemCaller::new(chain_spec.clone()),
+        )?;
+
+        Ok(balance_increments
+            .into_iter()
+            .map(|(address, amount)| (address, U256::from(amount)))
+            .collect())
+    }
+
+    /// Reads the claimable withdrawals of the given address from the deposit contract.
+    fn claimable_withdrawals(
+        &self,
+        address: Address,
+        block_id: BlockId,
+    ) -> eyre::Result<Option<U256>> {
+        let Some(header) = self.provider.header_by_id(block_id)? else {
+            return Ok(None);
+        };
+        let deposit_contract = self
+            .provider
+            .chain_spec()
+            .deposit_contract_address()
+            .ok_or_else(|| eyre::eyre!("deposit contract address is not set"))?;
+
+        let state = self.provider.state_by_block_id(block_id)?;
+        let mut db = State::builder()
+            .with_database(StateProviderDatabase::new(&state))
+            .build();
+        let mut evm = self.evm_config.evm_for_block(&mut db, &header);
+
+        // The state changes of the call are dropped, only its output is used
+        let ResultAndState { result, .. } = evm.transact_system_call(
+            alloy_eips::eip4788::SYSTEM_ADDRESS,
+            deposit_contract,
+            withdrawableAmountCall { _0: address }.abi_encode().into(),
+        )?;
+
+        match result {
+            ExecutionResult::Success { output, .. } => Ok(Some(
+                withdrawableAmountCall::abi_decode_returns(output.data())?,
+            )),
+            result => Err(eyre::eyre!("withdrawableAmount call failed: {result:?}")),
+        }
+    }
+}
+
+#[async_trait]
+impl<Provider> GnosisApiServer for GnosisRpc<Provider>
+where
+    Provider: StateProviderFactory
+        + BlockReaderIdExt<Block = GnosisBlock, Header = GnosisHeader, Receipt = Receipt>
+        + ChainSpecProvider<ChainSpec = GnosisChainSpec>
+        + Clone
+        + 'static,
+{
+    async fn get_proofs(
+        &self,
+        requests: Vec<ProofRequest>,
+        block_id: Option<BlockId>,
+    ) -> RpcResult<Vec<EIP1186AccountProofResponse>> {
+        if requests.len() > MAX_PROOF_REQUESTS {
+            return Err(invalid_params_rpc_err(format!(
+                "too many accounts requested: {} > {MAX_PROOF_REQUESTS}",
+                requests.len()
+            )));
+        }
+        let storage_keys = requests.iter().map(|r| r.storage_keys.len()).sum::<usize>();
+        if storage_keys > MAX_PROOF_STORAGE_KEYS {
+            return Err(invalid_params_rpc_err(format!(
+                "too many storage keys requested: {storage_keys} > {MAX_PROOF_STORAGE_KEYS}"
+            )));
+        }
+
+        let block_id = block_id.unwrap_or_default();
+        let number = self
+            .provider
+            .block_number_for_id(block_id)
+            .map_err(internal_rpc_err)?
+            .ok_or_else(|| invalid_params_rpc_err("block not found"))?;
+        let best_number = self
+            .provider
+            .best_block_number()
+            .map_err(internal_rpc_err)?;
+        if best_number.saturating_sub(number) > self.proof_window {
+            return Err(invalid_params_rpc_err(
+                "distance to target block exceeds maximum proof window",
+            ));
+        }
+
+        let _permit = self
+            .blocking_task_guard
+            .clone()
+            .acquire_owned()
+            .await
+            .map_err(internal_rpc_err)?;
+
+        // Proof generation walks the trie, keep it off the async runtime
+        let provider = self.provider.clone();
+        tokio::task::spawn_blocking(move || {
+            let state = provider
+                .state_by_block_id(number.into())
+                .map_err(internal_rpc_err)?;
+
+            requests
+                .into_iter()
+                .map(|request| {
+                    let slots = request
+                        .storage_keys
+                        .iter()
+                        .map(JsonStorageKey::as_b256)
+                        .collect::<Vec<_>>();
+                    let proof = state
+                        .proof(TrieInput::default(), request.address, &slots)
+                        .map_err(internal_rpc_err)?;
+                    Ok(proof.into_eip1186_response(request.storage_keys))
+                })
+                .collect()
+        })
+        .await
+        .map_err(internal_rpc_err)?
+    }
+
+    async fn get_block_rewards(&self, block_id: BlockId) -> RpcResult<Option<BlockRewards>> {
+        // Re-executes the block, limited like reth's trace and debug APIs
+        let _permit = self
+            .blocking_task_guard
+            .clone()
+            .acquire_owned()
+            .await
+            .map_err(internal_rpc_err)?;
+
+        let this = self.clone();
+        tokio::task::spawn_blocking(move || this.block_rewards(block_id))
+            .await
+            .map_err(internal_rpc_err)?
+            .map_err(internal_rpc_err)
+    }
+
+    async fn get_claimable_withdrawals(
+        &self,
+        address: Address,
+        block_id: Option<BlockId>,
+    ) -> RpcResult<Option<U256>> {
+        let this = self.clone();
+        tokio::task::spawn_blocking(move || {
+            this.claimable_withdrawals(address, block_id.unwrap_or_default())
+        })
+        .await
+        .map_err(internal_rpc_err)?
+        .map_err(internal_rpc_err)
+    }
+}
+
+/// Splits the fees paid in a block between the EIP-1559 fee collector and the fee recipient.
+///
+/// The fee collector is credited the base fee and blob fee for all gas used by the block, the fee
+/// recipient the priority fee of each transaction for the gas it used. Returns both, in order.
+fn split_fees<'a>(
+    base_fee: u64,
+    gas_used: u64,
+    blob_fee: u128,
+    blob_gas_used: u64,
+    transactions: impl IntoIterator<Item = (&'a TransactionSigned, &'a Receipt)>,
+) -> (U256, U256) {
+    let fee_collector_reward = U256::from(base_fee) * U256::from(gas_used)
+        + U256::from(blob_fee) * U256::from(blob_gas_used);
+
+    let mut validator_reward = U256::ZERO;
+    let mut cumulative_gas_used = 0;
+    for (tx, receipt) in transactions {
+        let gas_used = receipt.cumulative_gas_used - cumulative_gas_used;
+        cumulative_gas_used = receipt.cumulative_gas_used;
+        let tip = tx.effective_tip_per_gas(base_fee).unwrap_or_default();
+        validator_reward += U256::from(tip) * U256::from(gas_used);
+    }
+
+    (fee_collector_reward, validator_reward)
+}
+
+/// Wraps the given message into an invalid params JSON-RPC error.
+fn invalid_params_rpc_err(msg: impl ToString) -> ErrorObjectOwned {
+    ErrorObjectOwned::owned(INVALID_PARAMS_CODE, msg.to_string(), None::<()>)
+}
+
+/// Wraps the given error into an internal JSON-RPC error.
+fn internal_rpc_err(err: impl ToString) -> ErrorObjectOwned {
+    ErrorObjectOwned::owned(INTERNAL_ERROR_CODE, err.to_string(), None::<()>)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use alloy_consensus::{SignableTransaction, TxEip1559, TxLegacy};
+    use alloy_genesis::GenesisAccount;
+    use alloy_primitives::{address, hex, keccak256, Signature};
+    use alloy_sol_types::SolValue;
+    use alloy_trie::{proof::verify_proof, Nibbles, TrieAccount};
+    use reth_db_common::init::init_genesis;
+    use reth_ethereum_primitives::EthPrimitives;
+    use reth_primitives_traits::RecoveredBlock;
+    use reth_provider::{
+        providers::BlockchainProvider,
+        test_utils::{
+            create_test_provider_factory_with_node_types, ExtendedAccount, MockEthProvider,
+        },
+    };
+
+    use super::*;
+    use crate::{
+        primitives::header::GnosisHeaderBuilder, spec::chains::CHIADO_GENESIS, GnosisNode,
+    };
+
+    /// An RPC over a chain of empty blocks up to `best`, with a proof window of `proof_window`.
+    fn rpc_at(
+        best: u64,
+        proof_window: u64,
+    ) -> GnosisRpc<MockEthProvider<EthPrimitives, GnosisChainSpec>> {
+        let chain_spec = GnosisChainSpec::from(CHIADO_GENESIS.clone());
+        let provider = MockEthProvider::default().with_chain_spec(chain_spec.clone());
+        for number in 0..=best {
+            let header = GnosisHeaderBuilder::new().number(number).build();
+            provider.add_header(header.hash_slow(), header);
+        }
+
+        GnosisRpc::new(
+            provider,
+            GnosisEvmConfig::new(Arc::new(chain_spec)),
+            proof_window,
+            BlockingTaskGuard::new(1),
+        )
+    }
+
+    fn request(address: Address, storage_keys: usize) -> ProofRequest {
+        ProofRequest {
+            address,
+            storage_keys: vec![JsonStorageKey::from(B256::ZERO); storage_keys],
+        }
+    }
+
+    #[tokio::test]
+    async fn get_proofs_returns_one_proof_per_account() {
+        let rpc = rpc_at(10, 0);
+        let addresses = [Address::with_last_byte(1), Address::with_last_byte(2)];
+
+        let proofs = rpc
+            .get_proofs(addresses.map(|a| request(a, 1)).to_vec(), None)
+            .await
+            .unwrap();
+        assert_eq!(
+            proofs.iter().map(|p| p.address).collect::<Vec<_>>(),
+            addresses
+        );
+    }
+
+    #[tokio::test]
+    async fn get_proofs_enforces_limits() {
+        let rpc = rpc_at(10, 2);
+
+        let too_many_accounts = vec![request(Address::ZERO, 0); MAX_PROOF_REQUESTS + 1];
+        let err = rpc.get_proofs(too_many_accounts, None).await.unwrap_err();
+        assert_eq!(err.code(), INVALID_PARAMS_CODE);
+
+        let too_many_keys = vec![request(Address::ZERO, MAX_PROOF_STORAGE_KEYS / 2 + 1); 2];
+        let err = rpc.get_proofs(too_many_keys, None).await.unwrap_err();
+        assert_eq!(err.code(), INVALID_PARAMS_CODE);
+
+        let in_window = rpc
+            .get_proofs(vec![request(Address::ZERO, 0)], Some(8.into()))
+            .await;
+        assert!(in_window.is_ok());
+        let outside_window = rpc
+            .get_proofs(vec![request(Address::ZERO, 0)], Some(7.into()))
+            .await
+            .unwrap_err();
+        assert_eq!(outside_window.code(), INVALID_PARAMS_CODE);
+    }
+
+    /// An RPC over a freshly initialized database holding the Chiado genesis at `timestamp`,
+    /// with `accounts` added to the alloc.
+    fn rpc_with_state(
+        timestamp: u64,
+        accounts: impl IntoIterator<Item = (Address, GenesisAccount)>,
+    ) -> GnosisRpc<
+        impl StateProviderFactory
+            + BlockReaderIdExt<Block = GnosisBlock, Header = GnosisHeader, Receipt = Receipt>
+            + ChainSpecProvider<ChainSpec = GnosisChainSpec>
+            + Clone
+            + 'static,
+    > {
+        let mut genesis = CHIADO_GENESIS.clone();
+        genesis.timestamp = timestamp;
+        let chain_spec = Arc::new(GnosisChainSpec::from(genesis.extend_accounts(accounts)));
+
+        let factory =
+            create_test_provider_factory_with_node_types::<GnosisNode>(chain_spec.clone());
+        init_genesis(&factory).unwrap();
+        GnosisRpc::new(
+            BlockchainProvider::new(factory).unwrap(),
+            GnosisEvmConfig::new(chain_spec),
+            0,
+            BlockingTaskGuard::new(1),
+        )
+    }
+
+    #[tokio::test]
+    async fn get_proofs_verify_against_the_state_root() {
+        let cancun_time = CHIADO_GENESIS.config.cancun_time.unwrap();
+        let prague_time = CHIADO_GENESIS.config.prague_time.unwrap();
+        const SEEDED: Address = Address::with_last_byte(0x42);
+        let absent = Address::with_last_byte(0x43);
+
+        for timestamp in [cancun_time - 1, prague_time] {
+            let seeded_account = GenesisAccount::default()
+                .with_nonce(Some(1))
+                .with_balance(U256::from(1_000))
+                .with_storage(Some(BTreeMap::from([(
+                    B256::ZERO,
+                    B256::with_last_byte(7),
+                )])));
+            let rpc = rpc_with_state(timestamp, [(SEEDED, seeded_account)]);
+            let state_root = rpc.provider.chain_spec().genesis_header().state_root;
+            let requests = vec![
+                ProofRequest {
+                    address: SEEDED,
+                    storage_keys: vec![
+                        JsonStorageKey::from(B256::ZERO),
+                        JsonStorageKey::from(B256::with_last_byte(1)),
+                    ],
+                },
+                request(absent, 0),
+            ];
+            let proofs = rpc.get_proofs(requests, None).await.unwrap();
+
+            let seeded = &proofs[0];
+            assert_eq!((seeded.nonce, seeded.balance), (1, U256::from(1_000)));
+            let account = TrieAccount {
+                nonce: seeded.nonce,
+                balance: seeded.balance,
+                storage_root: seeded.storage_hash,
+                code_hash: seeded.code_hash,
+            };
+            verify_proof(
+                state_root,
+                Nibbles::unpack(keccak256(SEEDED)),
+                Some(alloy_rlp::encode(account)),
+                &seeded.account_proof,
+            )
+            .unwrap();
+
+            let values = seeded
+                .storage_proof
+                .iter()
+                .map(|proof| proof.value)
+                .collect::<Vec<_>>();
+            assert_eq!(values, [U256::from(7), U256::ZERO]);
+            for proof in &seeded.storage_proof {
+                let expected = (!proof.value.is_zero()).then(|| alloy_rlp::encode(proof.value));
+                verify_proof(
+                    seeded.storage_hash,
+                    Nibbles::unpack(keccak256(proof.key.as_b256())),
+                    expected,
+                    &proof.proof,
+                )
+                .unwrap();
+            }
+
+            // Exclusion proof for an account that is not in the alloc
+            verify_proof(
+                state_root,
+                Nibbles::unpack(keccak256(absent)),
+                None,
+                &proofs[1].account_proof,
+            )
+            .unwrap();
+        }
+    }
+
+    #[test]
+    fn block_rewards_contract_mints_are_the_rewards_contract_output() {
+        // Chiado `blockRewardsContract`
+        let rewards_contract = address!("0x2000000000000000000000000000000000000001");
+        let receiver = Address::with_last_byte(0x51);
+        let amount = U256::from(1_000_000);
+        // Returns `([receiver], [amount])` to any call: copies the 0xc0 bytes of ABI encoded
+        // output that follow the 12 bytes of code into memory, and returns them
+        let code = [
+            hex!("60c0600c60003960c06000f3").as_slice(),
+            &(vec![receiver], vec![amount]).abi_encode_params(),
+        ]
+        .concat();
+        // Before Shanghai, so that the deposit contract is not called
+        let rpc = rpc_with_state(
+            0,
+            [(
+                rewards_contract,
+                GenesisAccount::default().with_code(Some(code.into())),
+            )],
+        );
+
+        let chain_spec = rpc.provider.chain_spec();
+        let genesis = chain_spec.genesis_header();
+        let header = GnosisHeaderBuilder::new()
+            .parent_hash(chain_spec.genesis_hash())
+            .beneficiary(Address::with_last_byte(0x50))
+            .number(1)
+            .timestamp(genesis.timestamp + 5)
+            .gas_limit(genesis.gas_limit)
+            .base_fee_per_gas(genesis.base_fee_per_gas.unwrap_or_default())
+            .build();
+        let block = RecoveredBlock::new_unhashed(
+            GnosisBlock {
+                header,
+                body: Default::default(),
+            },
+            Vec::new(),
+        );
+
+        let mints = rpc.block_rewards_contract_mints(&block).unwrap();
+        assert_eq!(mints, BTreeMap::from([(receiver, amount)]));
+
+        let genesis_block = RecoveredBlock::new_unhashed(
+            GnosisBlock {
+                header: genesis.clone(),
+                body: Default::default(),
+            },
+            Vec::new(),
+        );
+        assert!(rpc.block_rewards_contract_mints(&genesis_block).is_err());
+    }
+
+    #[test]
+    fn claimable_withdrawals_are_read_from_the_deposit_contract() {
+        let rpc = rpc_at(10, 0);
+        let deposit_contract = rpc
+            .provider
+            .chain_spec()
+            .deposit_contract_address()
+            .unwrap();
+        // Returns 42 as a uint256 to any call
+        rpc.provider.add_account(
+            deposit_contract,
+            ExtendedAccount::new(0, U256::ZERO).with_bytecode(hex!("602a60005260206000f3").into()),
+        );
+
+        let address = Address::with_last_byte(1);
+        let claimable = rpc
+            .claimable_withdrawals(address, BlockId::number(5))
+            .unwrap();
+        assert_eq!(claimable, Some(U256::from(42)));
+
+        let missing_block = rpc
+            .claimable_withdrawals(address, BlockId::number(11))
+            .unwrap();
+        assert_eq!(missing_block, None);
+    }
+
+    #[test]
+    fn split_fees_between_fee_collector_and_validator() {
+        let base_fee = 10;
+        let signed =
+            |tx: TxEip1559| TransactionSigned::from(tx.into_signed(Signature::test_signature()));
+        let transactions = [
+            // Tip capped by the priority fee
+            signed(TxEip1559 {
+                max_fee_per_gas: 15,
+                max_priority_fee_per_gas: 2,
+                ..Default::default()
+            }),
+            // Tip is what the gas price leaves above the base fee
+            TransactionSigned::from(
+                TxLegacy {
+                    gas_price: 30,
+                    ..Default::default()
+                }
+                .into_signed(Signature::test_signature()),
+            ),
+            // Tip capped by the max fee
+            signed(TxEip1559 {
+                max_fee_per_gas: 12,
+                max_priority_fee_per_gas: 5,
+                ..Default::default()
+            }),
+        ];
+        let receipts = [21_000, 71_000, 100_000].map(|cumulative_gas_used| Receipt {
+            cumulative_gas_used,
+            ..Default::default()
+        });
+
+        let (fee_collector_reward, validator_reward) = split_fees(
+            base_fee,
+            100_000,
+            3,
+            131_072,
+            transactions.iter().zip(&receipts),
+        );
+        assert_eq!(fee_collector_reward, U256::from(10 * 100_000 + 3 * 131_072));
+        assert_eq!(
+            validator_reward,
+            U256::from(2 * 21_000 + 20 * 50_000 + 2 * 29_000)
+        );
+    }
+}
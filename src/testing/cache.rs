@@ -0,0 +1,147 @@
+//! Caching of passing test cases between runs.
+
+use crate::testing::result::CaseResult;
+use alloy_primitives::{keccak256, B256};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    env, fs,
+    path::{Path, PathBuf},
+    sync::OnceLock,
+};
+
+/// Remembers which test cases passed, keyed by the hash of their fixture file.
+///
+/// A case is only skipped if its fixture is byte-identical to the one that passed, with the same
+/// test binary. Any change to the node rebuilds the binary, which drops the whole cache.
+#[derive(Debug)]
+pub struct ResultCache {
+    /// The file the cache is persisted to.
+    file: PathBuf,
+    /// The passing cases and the build they passed with.
+    entries: CacheEntries,
+}
+
+/// The persisted contents of a [`ResultCache`].
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+struct CacheEntries {
+    /// Hash of the test binary the cases passed with.
+    build: B256,
+    /// Hash of every fixture file that passed, keyed by its path.
+    passed: BTreeMap<PathBuf, B256>,
+}
+
+impl ResultCache {
+    /// Load the cache of the given suite from `dir`, starting empty if there is none yet or it
+    /// was written by another build.
+    pub fn load(dir: &Path, suite_name: &str) -> Self {
+        Self::load_for_build(dir, suite_name, build_fingerprint())
+    }
+
+    fn load_for_build(dir: &Path, suite_name: &str, build: B256) -> Self {
+        let file = dir.join(format!("{}.json", suite_name.replace('/', "_")));
+        let passed = fs::read_to_string(&file)
+            .ok()
+            .and_then(|s| serde_json::from_str::<CacheEntries>(&s).ok())
+            .filter(|entries| entries.build == build)
+            .map(|entries| entries.passed)
+            .unwrap_or_default();
+
+        Self {
+            file,
+            entries: CacheEntries { build, passed },
+        }
+    }
+
+    /// Returns whether the case at `path` passed before and its fixture has not changed since.
+    pub fn is_unchanged_pass(&self, path: &Path) -> bool {
+        self.entries
+            .passed
+            .get(path)
+            .is_some_and(|hash| hash_file(path).as_ref() == Some(hash))
+    }
+
+    /// Record the outcome of the given results, forgetting cases that did not pass.
+    pub fn update(&mut self, results: &[CaseResult]) {
+        for case in results {
+            match (&case.result, hash_file(&case.path)) {
+                (Ok(()), Some(hash)) => self.entries.passed.insert(case.path.clone(), hash),
+                _ => self.entries.passed.remove(&case.path),
+            };
+        }
+    }
+
+    /// Persist the cache to disk.
+    pub fn save(&self) -> std::io::Result<()> {
+        if let Some(parent) = self.file.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&self.file, serde_json::to_string_pretty(&self.entries)?)
+    }
+}
+
+/// Hash of the running test binary, computed once per run.
+fn build_fingerprint() -> B256 {
+    static FINGERPRINT: OnceLock<B256> = OnceLock::new();
+    *FINGERPRINT.get_or_init(|| {
+        env::current_exe()
+            .ok()
+            .and_then(|exe| hash_file(&exe))
+            .expect("test binary should be readable")
+    })
+}
+
+/// Hash the contents of the file at `path`.
+fn hash_file(path: &Path) -> Option<B256> {
+    fs::read(path).ok().map(keccak256)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::Error;
+    use std::time::Duration;
+
+    fn result(path: &Path, result: Result<(), Error>) -> CaseResult {
+        CaseResult {
+            desc: String::new(),
+            path: path.into(),
+            result,
+            duration: Duration::ZERO,
+            cached: false,
+        }
+    }
+
+    #[test]
+    fn cache_roundtrip_and_invalidation() {
+        let dir = env::temp_dir().join(format!("gnosis-cache-{}", std::process::id()));
+        let passing = dir.join("passing.json");
+        let failing = dir.join("failing.json");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(&passing, "{}").unwrap();
+        fs::write(&failing, "{}").unwrap();
+        let build = B256::with_last_byte(1);
+
+        let mut cache = ResultCache::load_for_build(&dir, "gnosis/suite", build);
+        assert!(!cache.is_unchanged_pass(&passing));
+        cache.update(&[
+            result(&passing, Ok(())),
+            result(&failing, Err(Error::Custom("failed".to_string()))),
+        ]);
+        cache.save().unwrap();
+
+        let cache = ResultCache::load_for_build(&dir, "gnosis/suite", build);
+        assert!(cache.is_unchanged_pass(&passing));
+        assert!(!cache.is_unchanged_pass(&failing));
+
+        // Another build drops everything
+        let other_build = ResultCache::load_for_build(&dir, "gnosis/suite", B256::ZERO);
+        assert!(!other_build.is_unchanged_pass(&passing));
+
+        // A changed fixture has to run again
+        fs::write(&passing, "{ }").unwrap();
+        assert!(!cache.is_unchanged_pass(&passing));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
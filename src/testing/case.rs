@@ -1,9 +1,14 @@
 //! Test case definitions
 
-use crate::testing::result::{CaseResult, Error};
+use crate::testing::{
+    cache::ResultCache,
+    result::{CaseResult, Error},
+};
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 use std::{
     fmt::Debug,
     path::{Path, PathBuf},
+    time::Instant,
 };
 
 /// A single test case, capable of loading a JSON description of itself and running it.
@@ -22,6 +27,11 @@ pub trait Case: Debug + Sync + Sized {
 
     /// Run the test.
     fn run(&self) -> Result<(), Error>;
+
+    /// Whether the test is skipped when run. Skipped tests are never reported from the cache.
+    fn is_skipped(&self) -> bool {
+        false
+    }
 }
 
 /// A container for multiple test cases.
@@ -32,11 +42,30 @@ pub struct Cases<T> {
 }
 
 impl<T: Case> Cases<T> {
-    /// Run the contained test cases.
-    pub fn run(&self) -> Vec<CaseResult> {
-        self.test_cases
-            .iter()
-            .map(|(path, case)| CaseResult::new(path, case, case.run()))
-            .collect()
+    /// Run the contained test cases on a pool of `threads` threads (`0` picks the number of
+    /// CPUs), skipping the ones the given cache knows to have passed unchanged.
+    pub fn run_parallel(&self, threads: usize, cache: Option<&ResultCache>) -> Vec<CaseResult> {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build()
+            .expect("test thread pool should build");
+
+        pool.install(|| {
+            self.test_cases
+                .par_iter()
+                .map(|(path, case)| match cache {
+                    Some(cache) if !case.is_skipped() && cache.is_unchanged_pass(path) => {
+                        CaseResult::cached(path, case)
+                    }
+                    _ => Self::run_case(path, case),
+                })
+                .collect()
+        })
+    }
+
+    fn run_case(path: &Path, case: &T) -> CaseResult {
+        let started = Instant::now();
+        let result = case.run();
+        CaseResult::new(path, case, result).with_duration(started.elapsed())
     }
 }
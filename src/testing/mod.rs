@@ -1,5 +1,6 @@
 #![allow(dead_code)]
 #![cfg(test)]
+pub mod cache;
 pub mod case;
 pub mod result;
 pub mod suite;
@@ -3,7 +3,12 @@
 use crate::testing::Case;
 use reth_db::DatabaseError;
 use reth_provider::ProviderError;
-use std::path::{Path, PathBuf};
+use std::{
+    fmt::Write,
+    fs,
+    path::{Path, PathBuf},
+    time::Duration,
+};
 use thiserror::Error;
 
 /// Test errors
@@ -64,6 +69,10 @@ pub struct CaseResult {
     pub path: PathBuf,
     /// The result of the test.
     pub result: Result<(), Error>,
+    /// How long the test took to run.
+    pub duration: Duration,
+    /// Whether the result was taken from the cache instead of running the test.
+    pub cached: bool,
 }
 
 impl CaseResult {
@@ -73,8 +82,24 @@ impl CaseResult {
             desc: case.description(),
             path: path.into(),
             result,
+            duration: Duration::ZERO,
+            cached: false,
         }
     }
+
+    /// Create a passing result for a test that was not run because it passed unchanged before.
+    pub fn cached(path: &Path, case: &impl Case) -> Self {
+        Self {
+            cached: true,
+            ..Self::new(path, case, Ok(()))
+        }
+    }
+
+    /// Set how long the test took to run.
+    pub const fn with_duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
 }
 
 /// Assert that all the given tests passed and print the results to stdout.
@@ -115,11 +140,12 @@ pub(crate) fn print_results(
 ) {
     println!("Suite: {suite_name} (at {})", path.display());
     println!(
-        "Ran {} tests ({} passed, {} failed, {} skipped)",
+        "Ran {} tests ({} passed, {} failed, {} skipped, {} cached)",
         passed.len() + failed.len() + skipped.len(),
         passed.len(),
         failed.len(),
-        skipped.len()
+        skipped.len(),
+        passed.iter().filter(|case| case.cached).count()
     );
 
     for case in skipped {
@@ -136,3 +162,100 @@ pub(crate) fn print_results(
         );
     }
 }
+
+/// Write the given test results as a JUnit XML report to `file`.
+pub(crate) fn write_junit_report(
+    suite_name: &str,
+    file: &Path,
+    results: &[CaseResult],
+) -> std::io::Result<()> {
+    if let Some(parent) = file.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(file, junit_report(suite_name, results))
+}
+
+/// Render the given test results as a JUnit XML document.
+fn junit_report(suite_name: &str, results: &[CaseResult]) -> String {
+    let (passed, failed, skipped) = categorize_results(results);
+    let total_time: Duration = results.iter().map(|case| case.duration).sum();
+
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    let _ = writeln!(
+        xml,
+        r#"<testsuite name="{}" tests="{}" failures="{}" skipped="{}" time="{:.3}">"#,
+        xml_escape(suite_name),
+        passed.len() + failed.len() + skipped.len(),
+        failed.len(),
+        skipped.len(),
+        total_time.as_secs_f64()
+    );
+
+    for case in results {
+        let _ = write!(
+            xml,
+            r#"  <testcase name="{}" classname="{}" time="{:.3}">"#,
+            xml_escape(&case.path.display().to_string()),
+            xml_escape(suite_name),
+            case.duration.as_secs_f64()
+        );
+        match &case.result {
+            Ok(()) => {}
+            Err(Error::Skipped) => xml.push_str("<skipped/>"),
+            Err(error) => {
+                let _ = write!(
+                    xml,
+                    r#"<failure message="{}"/>"#,
+                    xml_escape(&error.to_string())
+                );
+            }
+        }
+        xml.push_str("</testcase>\n");
+    }
+
+    xml.push_str("</testsuite>\n");
+    xml
+}
+
+/// Escape the characters that are not allowed verbatim in XML attributes.
+fn xml_escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn junit_report_counts_and_escapes() {
+        let case = |path: &str, result| CaseResult {
+            desc: String::new(),
+            path: path.into(),
+            result,
+            duration: Duration::from_millis(1500),
+            cached: false,
+        };
+        let results = [
+            case("a.json", Ok(())),
+            case("b.json", Err(Error::Skipped)),
+            case("c.json", Err(Error::Assertion("<1> & \"2\"".to_string()))),
+        ];
+
+        let xml = junit_report("GeneralStateTests/stExample", &results);
+
+        assert!(xml.contains(r#"tests="3" failures="1" skipped="1" time="4.500""#));
+        assert!(xml.contains(r#"<testcase name="b.json" classname="GeneralStateTests/stExample" time="1.500"><skipped/></testcase>"#));
+        assert!(xml.contains(r#"<failure message="test failed: &lt;1&gt; &amp; &quot;2&quot;"/>"#));
+    }
+}
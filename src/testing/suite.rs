@@ -1,10 +1,15 @@
 //! Abstractions for groups of tests.
 
 use crate::testing::{
+    cache::ResultCache,
     case::{Case, Cases},
-    result::assert_tests_pass,
+    cases::blockchain_test::GNOSIS_FIXTURES_DIR,
+    result::{assert_tests_pass, write_junit_report},
+};
+use std::{
+    env,
+    path::{Path, PathBuf},
 };
-use std::path::{Path, PathBuf};
 use walkdir::{DirEntry, WalkDir};
 
 /// A collection of tests.
@@ -19,6 +24,7 @@ pub trait Suite {
     /// - `GeneralStateTests`
     /// - `BlockchainTests/InvalidBlocks`
     /// - `BlockchainTests/TransitionTests`
+    /// - `gnosis/genesis`, for tests under [`GNOSIS_FIXTURES_DIR`]
     fn suite_name(&self) -> String;
 
     /// Load an run each contained test case.
@@ -32,14 +38,14 @@ pub trait Suite {
         //     .join("fixtures")
         //     .join(self.suite_name());
         dbg!("suit path", self.suite_name());
-        let suite_path = match self.suite_name().starts_with("blockchain_tests") {
-            true => PathBuf::from(env!("CARGO_MANIFEST_DIR"))
-                .join("fixtures")
-                .join(self.suite_name()),
-            false => PathBuf::from(env!("CARGO_MANIFEST_DIR"))
-                .join("ethereum-tests")
-                .join(self.suite_name()),
+        let fixtures_dir = if let Some(suite) = self.suite_name().strip_prefix("gnosis/") {
+            PathBuf::from(GNOSIS_FIXTURES_DIR).join(suite)
+        } else if self.suite_name().starts_with("blockchain_tests") {
+            PathBuf::from("fixtures").join(self.suite_name())
+        } else {
+            PathBuf::from("ethereum-tests").join(self.suite_name())
         };
+        let suite_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(fixtures_dir);
 
         // Verify that the path exists
         assert!(
@@ -57,13 +63,60 @@ pub trait Suite {
             .collect();
 
         // Run the test cases and collect the results
-        let results = Cases { test_cases }.run();
+        let options = SuiteOptions::from_env();
+        let mut cache = options
+            .cache_dir
+            .as_deref()
+            .map(|dir| ResultCache::load(dir, &self.suite_name()));
+        let results = Cases { test_cases }.run_parallel(options.threads, cache.as_ref());
+
+        if let Some(cache) = &mut cache {
+            cache.update(&results);
+            cache.save().expect("test result cache should be writable");
+        }
+
+        if let Some(dir) = &options.junit_dir {
+            let file = dir.join(format!("{}.xml", self.suite_name().replace('/', "_")));
+            write_junit_report(&self.suite_name(), &file, &results)
+                .expect("JUnit report should be writable");
+        }
 
         // Assert that all tests in the suite pass
         assert_tests_pass(&self.suite_name(), &suite_path, &results);
     }
 }
 
+/// Options for running a suite, read from the environment since `cargo test` offers no way to
+/// pass arguments to individual tests.
+#[derive(Debug, Default)]
+struct SuiteOptions {
+    /// `GNOSIS_TEST_THREADS`: number of test cases to run at the same time, defaults to the
+    /// number of CPUs.
+    threads: usize,
+    /// `GNOSIS_TEST_CACHE_DIR`: where to remember passing cases, so unchanged ones are skipped
+    /// on the next run of the same test binary.
+    cache_dir: Option<PathBuf>,
+    /// `GNOSIS_TEST_JUNIT_DIR`: where to write a JUnit XML report per suite.
+    junit_dir: Option<PathBuf>,
+}
+
+impl SuiteOptions {
+    fn from_env() -> Self {
+        Self {
+            threads: env::var("GNOSIS_TEST_THREADS")
+                .ok()
+                .map(|threads| {
+                    threads.parse().unwrap_or_else(|_| {
+                        panic!("GNOSIS_TEST_THREADS should be a number of threads, got {threads:?}")
+                    })
+                })
+                .unwrap_or_default(),
+            cache_dir: env::var_os("GNOSIS_TEST_CACHE_DIR").map(PathBuf::from),
+            junit_dir: env::var_os("GNOSIS_TEST_JUNIT_DIR").map(PathBuf::from),
+        }
+    }
+}
+
 /// Recursively find all files with a given extension.
 fn find_all_files_with_extension(path: &Path, extension: &str) -> Vec<PathBuf> {
     WalkDir::new(path)
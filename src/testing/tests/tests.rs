@@ -135,3 +135,12 @@ mod general_state_tests {
         general_state_test!(vm_tests, VMTests);
     }
 }
+
+mod gnosis_tests {
+    use crate::testing::{cases::blockchain_test::BlockchainTests, suite::Suite};
+
+    #[test]
+    fn genesis() {
+        BlockchainTests::new("gnosis/genesis".to_string()).run();
+    }
+}